@@ -0,0 +1,105 @@
+// 把构建信息嵌入合约，见 lib.rs 中的 build_info。
+//
+// release 构建时缺少任何一项都会失败，避免部署的 Wasm 无法对应到审计过的源码版本；
+// 本地构建可以设置 BUILD_INFO_ALLOW_UNKNOWN=1，缺少的项记为 "unknown"。
+// 没有 git 仓库或没有安装 cargo-contract 时，可以用 GIT_COMMIT_HASH、CARGO_CONTRACT_VERSION 直接给出
+use std::env;
+use std::process::Command;
+
+const UNKNOWN: &str = "unknown";
+
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    Some(text).filter(|text| !text.is_empty())
+}
+
+fn from_env(name: &str) -> Option<String> {
+    println!("cargo:rerun-if-env-changed={}", name);
+    env::var(name).ok().map(|value| value.trim().to_string()).filter(|value| !value.is_empty())
+}
+
+// 工作区有未提交的改动时加上 -dirty，这样的构建不对应任何一个提交
+fn git_commit() -> Option<String> {
+    if let Some(commit) = from_env("GIT_COMMIT_HASH") {
+        return Some(commit);
+    }
+    for path in ["HEAD", "index"] {
+        if let Some(path) = run("git", &["rev-parse", "--git-path", path]) {
+            println!("cargo:rerun-if-changed={}", path);
+        }
+    }
+    let commit = run("git", &["rev-parse", "HEAD"])?;
+    let dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .map(|output| !output.stdout.is_empty())
+        .unwrap_or(false);
+    Some(if dirty { commit + "-dirty" } else { commit })
+}
+
+// cargo-contract 调用 cargo 时不传自己的版本，只能另外运行一次
+fn cargo_contract_version() -> Option<String> {
+    from_env("CARGO_CONTRACT_VERSION").or_else(|| {
+        let version = run("cargo-contract", &["contract", "--version"])?;
+        Some(version.trim_start_matches("cargo-contract").trim().to_string())
+    })
+}
+
+fn rustc_version() -> Option<String> {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    run(&rustc, &["--version"])
+}
+
+// Cargo.toml 的 [features] 中声明的 feature。可选依赖也会以隐式 feature 传给构建脚本，不计入
+fn declared_features() -> Vec<String> {
+    let manifest = std::path::Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap_or_default()).join("Cargo.toml");
+    println!("cargo:rerun-if-changed={}", manifest.display());
+    let manifest = std::fs::read_to_string(manifest).unwrap_or_default();
+    manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[features]")
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with('['))
+        .filter_map(|line| line.split_once('=').map(|(name, _)| name.trim().to_string()))
+        .filter(|name| !name.is_empty() && !name.starts_with('#'))
+        .collect()
+}
+
+// cargo 把启用的 feature 以 CARGO_FEATURE_<NAME> 传给构建脚本，名称已转为大写、- 转为 _
+fn features() -> String {
+    let mut features: Vec<String> = declared_features()
+        .into_iter()
+        .filter(|feature| env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"))).is_some())
+        .collect();
+    features.sort();
+    features.join(",")
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=lib.rs");
+    let allow_unknown = from_env("BUILD_INFO_ALLOW_UNKNOWN").is_some_and(|value| value != "0");
+    let release = env::var("PROFILE").is_ok_and(|profile| profile == "release");
+
+    let values = [
+        ("BUILD_GIT_COMMIT", git_commit()),
+        ("BUILD_RUSTC_VERSION", rustc_version()),
+        ("BUILD_CARGO_CONTRACT_VERSION", cargo_contract_version()),
+    ];
+    let missing: Vec<&str> = values.iter().filter(|(_, value)| value.is_none()).map(|(name, _)| *name).collect();
+    if release && !allow_unknown && !missing.is_empty() {
+        panic!(
+            "build info unavailable for a release build: {} (set GIT_COMMIT_HASH / CARGO_CONTRACT_VERSION, \
+             or BUILD_INFO_ALLOW_UNKNOWN=1 for a local build)",
+            missing.join(", ")
+        );
+    }
+    for (name, value) in values {
+        println!("cargo:rustc-env={}={}", name, value.as_deref().unwrap_or(UNKNOWN));
+    }
+    println!("cargo:rustc-env=BUILD_FEATURES={}", features());
+}
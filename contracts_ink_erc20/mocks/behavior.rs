@@ -0,0 +1,127 @@
+use crate::{AccountId, MockContract};
+use scale::{Decode, Encode};
+
+/// 可编程的响应方式，设置后对之后的每次调用生效，直到再次设置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    /// 执行成功，返回 set_output 设置的编码结果（默认为空，即 ()）
+    Succeed,
+    /// 执行成功并返回编码后的 false
+    ReturnFalse,
+    /// 执行失败回滚
+    Revert,
+    /// 需要消耗指定的 gas，调用方给的 gas 上限不足时执行失败，否则按 Succeed 处理；
+    /// u64::MAX 相当于死循环
+    ConsumeGas(u64),
+    /// 回调发起调用的合约。ink! 合约默认禁止重入，这样的回调总是失败，
+    /// 因此被调用合约执行失败
+    Reenter,
+}
+
+/// 模拟合约收到的一次调用
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedCall {
+    pub caller: AccountId,
+    pub selector: [u8; 4],
+    pub input: Vec<u8>,
+    pub gas_limit: u64,
+    pub succeeded: bool,
+}
+
+/// 按编程的响应方式处理任意选择器的模拟合约，用于测试外部调用失败时的处理
+#[derive(Debug, Clone)]
+pub struct MockBehavior {
+    response: Response,
+    output: Vec<u8>,
+    calls: Vec<RecordedCall>,
+}
+
+impl Default for MockBehavior {
+    fn default() -> Self {
+        Self::new(Response::Succeed)
+    }
+}
+
+impl MockBehavior {
+    pub fn new(response: Response) -> Self {
+        Self {
+            response,
+            output: Vec::new(),
+            calls: Vec::new(),
+        }
+    }
+
+    pub fn set_response(&mut self, response: Response) {
+        self.response = response;
+    }
+
+    pub fn set_output<T: Encode>(&mut self, output: T) {
+        self.output = output.encode();
+    }
+
+    /// 收到的所有调用，包括执行失败的
+    pub fn calls(&self) -> &[RecordedCall] {
+        &self.calls
+    }
+
+    /// 每次调用收到的 gas 上限
+    pub fn gas_limits(&self) -> Vec<u64> {
+        self.calls.iter().map(|call| call.gas_limit).collect()
+    }
+
+    /// 以指定选择器成功执行的调用参数
+    pub fn succeeded_calls<T: Decode>(&self, selector: [u8; 4]) -> Vec<T> {
+        self.calls
+            .iter()
+            .filter(|call| call.succeeded && call.selector == selector)
+            .map(|call| T::decode(&mut &call.input[..]).expect("unexpected call arguments"))
+            .collect()
+    }
+}
+
+impl MockContract for MockBehavior {
+    fn dispatch(&mut self, caller: AccountId, selector: [u8; 4], input: &[u8], gas_limit: u64) -> Result<Vec<u8>, ()> {
+        let result = match self.response {
+            Response::Succeed => Ok(self.output.clone()),
+            Response::ReturnFalse => Ok(false.encode()),
+            Response::ConsumeGas(needed) if gas_limit >= needed => Ok(self.output.clone()),
+            Response::ConsumeGas(_) | Response::Revert | Response::Reenter => Err(()),
+        };
+        self.calls.push(RecordedCall {
+            caller,
+            selector,
+            input: input.to_vec(),
+            gas_limit,
+            succeeded: result.is_ok(),
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn responses_can_be_programmed() {
+        let caller = AccountId::from([0x7; 32]);
+        let mut mock = MockBehavior::default();
+        mock.set_output(42u32);
+        assert_eq!(mock.dispatch(caller, [1; 4], &7u8.encode(), 10), Ok(42u32.encode()));
+
+        mock.set_response(Response::ReturnFalse);
+        assert_eq!(mock.dispatch(caller, [2; 4], &[], 10), Ok(false.encode()));
+
+        mock.set_response(Response::ConsumeGas(100));
+        assert_eq!(mock.dispatch(caller, [1; 4], &8u8.encode(), 99), Err(()));
+        assert_eq!(mock.dispatch(caller, [1; 4], &9u8.encode(), 100), Ok(42u32.encode()));
+
+        for response in [Response::Revert, Response::Reenter] {
+            mock.set_response(response);
+            assert_eq!(mock.dispatch(caller, [1; 4], &[], 10), Err(()));
+        }
+
+        assert_eq!(mock.gas_limits(), vec![10, 10, 99, 100, 10, 10]);
+        assert_eq!(mock.succeeded_calls::<u8>([1; 4]), vec![7, 9]);
+    }
+}
@@ -0,0 +1,26 @@
+//! 跨合约测试共用的模拟合约。
+//!
+//! ink! 的 off-chain 测试环境不能执行真实的跨合约调用，这里的模拟合约在选择器和
+//! SCALE 编码层面模拟被调用合约的行为：测试框架把调用方发出的选择器与参数交给
+//! `MockContract::dispatch`，再把返回的编码结果交还给调用方。
+
+mod behavior;
+mod oracle;
+mod psp22;
+mod verifier;
+
+pub use behavior::{MockBehavior, RecordedCall, Response};
+pub use oracle::{MockOracle, LATEST_PRICE};
+pub use psp22::{selectors, MockPsp22, Psp22Error};
+pub use verifier::{MockVerifier, VERIFY};
+
+pub type AccountId = ink_env::AccountId;
+pub type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
+
+/// 可以被测试框架部署到某个账户上的模拟合约
+pub trait MockContract {
+    /// 处理一次调用：caller 为发起调用的合约，返回编码后的结果，Err 表示被调用合约执行失败。
+    /// 错误不携带信息，与链上调用方只能观察到执行失败一致
+    #[allow(clippy::result_unit_err)]
+    fn dispatch(&mut self, caller: AccountId, selector: [u8; 4], input: &[u8], gas_limit: u64) -> Result<Vec<u8>, ()>;
+}
@@ -0,0 +1,71 @@
+use crate::{AccountId, MockContract};
+use scale::Encode;
+
+/// 价格预言机 latest_price() -> (u128, Timestamp) 的选择器
+pub const LATEST_PRICE: [u8; 4] = ink_lang::selector_bytes!("latest_price");
+
+/// 返回可编程价格与更新时间的价格预言机，可以设置为执行失败以模拟预言机故障
+#[derive(Debug, Clone, Default)]
+pub struct MockOracle {
+    price: u128,
+    updated_at: u64,
+    reverting: bool,
+    gas_limits: Vec<u64>,
+}
+
+impl MockOracle {
+    pub fn new(price: u128, updated_at: u64) -> Self {
+        Self {
+            price,
+            updated_at,
+            ..Self::default()
+        }
+    }
+
+    pub fn set_price(&mut self, price: u128, updated_at: u64) {
+        self.price = price;
+        self.updated_at = updated_at;
+    }
+
+    pub fn set_reverting(&mut self, reverting: bool) {
+        self.reverting = reverting;
+    }
+
+    /// 每次调用收到的 gas 上限
+    pub fn gas_limits(&self) -> &[u64] {
+        &self.gas_limits
+    }
+}
+
+impl MockContract for MockOracle {
+    fn dispatch(&mut self, _caller: AccountId, selector: [u8; 4], _input: &[u8], gas_limit: u64) -> Result<Vec<u8>, ()> {
+        self.gas_limits.push(gas_limit);
+        if self.reverting || selector != LATEST_PRICE {
+            return Err(());
+        }
+        Ok((self.price, self.updated_at).encode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scale::Decode;
+
+    #[test]
+    fn oracle_reports_price_or_reverts() {
+        let caller = AccountId::from([0x7; 32]);
+        let mut oracle = MockOracle::new(5, 100);
+        let output = oracle.dispatch(caller, LATEST_PRICE, &[], 10).unwrap();
+        assert_eq!(<(u128, u64)>::decode(&mut &output[..]), Ok((5, 100)));
+
+        oracle.set_price(6, 200);
+        let output = oracle.dispatch(caller, LATEST_PRICE, &[], 10).unwrap();
+        assert_eq!(<(u128, u64)>::decode(&mut &output[..]), Ok((6, 200)));
+
+        oracle.set_reverting(true);
+        assert_eq!(oracle.dispatch(caller, LATEST_PRICE, &[], 10), Err(()));
+        assert_eq!(oracle.dispatch(caller, [0; 4], &[], 10), Err(()));
+        assert_eq!(oracle.gas_limits(), &[10, 10, 10, 10]);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{AccountId, Balance, MockContract};
+use scale::{Decode, Encode};
+use std::collections::BTreeMap;
+
+/// 模拟代币支持的消息选择器
+pub mod selectors {
+    pub const TOTAL_SUPPLY: [u8; 4] = ink_lang::selector_bytes!("PSP22::total_supply");
+    pub const BALANCE_OF: [u8; 4] = ink_lang::selector_bytes!("PSP22::balance_of");
+    pub const ALLOWANCE: [u8; 4] = ink_lang::selector_bytes!("PSP22::allowance");
+    pub const TRANSFER: [u8; 4] = ink_lang::selector_bytes!("PSP22::transfer");
+    pub const TRANSFER_FROM: [u8; 4] = ink_lang::selector_bytes!("PSP22::transfer_from");
+    pub const APPROVE: [u8; 4] = ink_lang::selector_bytes!("PSP22::approve");
+    /// 不带 trait 前缀的 balance_of，与本仓库的 ERC20 合约相同，供按该选择器查询余额的调用方使用
+    pub const PLAIN_BALANCE_OF: [u8; 4] = ink_lang::selector_bytes!("balance_of");
+    /// 不带 trait 前缀、也没有 data 参数的 transfer_from(from, to, value)，与本仓库的 ERC20 合约相同
+    pub const PLAIN_TRANSFER_FROM: [u8; 4] = ink_lang::selector_bytes!("transfer_from");
+}
+
+/// 与 PSP22 标准相同顺序的错误类型，保证编码一致
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum Psp22Error {
+    Custom(String),
+    InsufficientBalance,
+    InsufficientAllowance,
+}
+
+/// 最小化的 PSP22 代币，只实现余额、授权和转账
+#[derive(Debug, Default, Clone)]
+pub struct MockPsp22 {
+    total_supply: Balance,
+    balances: BTreeMap<AccountId, Balance>,
+    allowances: BTreeMap<(AccountId, AccountId), Balance>,
+}
+
+impl MockPsp22 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_balances(balances: Vec<(AccountId, Balance)>) -> Self {
+        let mut token = Self::new();
+        for (account, value) in balances {
+            token.mint(account, value);
+        }
+        token
+    }
+
+    pub fn mint(&mut self, account: AccountId, value: Balance) {
+        self.total_supply += value;
+        *self.balances.entry(account).or_insert(0) += value;
+    }
+
+    pub fn total_supply(&self) -> Balance {
+        self.total_supply
+    }
+
+    pub fn balance_of(&self, owner: AccountId) -> Balance {
+        *self.balances.get(&owner).unwrap_or(&0)
+    }
+
+    pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+        *self.allowances.get(&(owner, spender)).unwrap_or(&0)
+    }
+
+    pub fn transfer(&mut self, from: AccountId, to: AccountId, value: Balance) -> Result<(), Psp22Error> {
+        let from_balance = self.balance_of(from);
+        if from_balance < value {
+            return Err(Psp22Error::InsufficientBalance);
+        }
+        self.balances.insert(from, from_balance - value);
+        *self.balances.entry(to).or_insert(0) += value;
+        Ok(())
+    }
+
+    pub fn approve(&mut self, owner: AccountId, spender: AccountId, value: Balance) {
+        self.allowances.insert((owner, spender), value);
+    }
+
+    pub fn transfer_from(
+        &mut self,
+        spender: AccountId,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+    ) -> Result<(), Psp22Error> {
+        let allowance = self.allowance(from, spender);
+        if allowance < value {
+            return Err(Psp22Error::InsufficientAllowance);
+        }
+        self.transfer(from, to, value)?;
+        self.approve(from, spender, allowance - value);
+        Ok(())
+    }
+}
+
+impl MockContract for MockPsp22 {
+    fn dispatch(&mut self, caller: AccountId, selector: [u8; 4], input: &[u8], _gas_limit: u64) -> Result<Vec<u8>, ()> {
+        let input = &mut &input[..];
+        let output = match selector {
+            selectors::TOTAL_SUPPLY => self.total_supply().encode(),
+            selectors::BALANCE_OF | selectors::PLAIN_BALANCE_OF => {
+                let owner = AccountId::decode(input).map_err(|_| ())?;
+                self.balance_of(owner).encode()
+            }
+            selectors::ALLOWANCE => {
+                let (owner, spender) = <(AccountId, AccountId)>::decode(input).map_err(|_| ())?;
+                self.allowance(owner, spender).encode()
+            }
+            selectors::TRANSFER => {
+                let (to, value, _data) = <(AccountId, Balance, Vec<u8>)>::decode(input).map_err(|_| ())?;
+                self.transfer(caller, to, value).encode()
+            }
+            selectors::TRANSFER_FROM => {
+                let (from, to, value, _data) =
+                    <(AccountId, AccountId, Balance, Vec<u8>)>::decode(input).map_err(|_| ())?;
+                self.transfer_from(caller, from, to, value).encode()
+            }
+            selectors::PLAIN_TRANSFER_FROM => {
+                let (from, to, value) = <(AccountId, AccountId, Balance)>::decode(input).map_err(|_| ())?;
+                self.transfer_from(caller, from, to, value).encode()
+            }
+            selectors::APPROVE => {
+                let (spender, value) = <(AccountId, Balance)>::decode(input).map_err(|_| ())?;
+                self.approve(caller, spender, value);
+                Ok::<(), Psp22Error>(()).encode()
+            }
+            _ => return Err(()),
+        };
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_transfers_between_accounts() {
+        let alice = AccountId::from([0x1; 32]);
+        let bob = AccountId::from([0x2; 32]);
+        let mut token = MockPsp22::with_balances(vec![(alice, 100)]);
+
+        let output = token
+            .dispatch(alice, selectors::TRANSFER, &(bob, 30 as Balance, Vec::<u8>::new()).encode(), 0)
+            .unwrap();
+        assert_eq!(Result::<(), Psp22Error>::decode(&mut &output[..]), Ok(Ok(())));
+
+        let output = token
+            .dispatch(alice, selectors::TRANSFER, &(bob, 71 as Balance, Vec::<u8>::new()).encode(), 0)
+            .unwrap();
+        assert_eq!(
+            Result::<(), Psp22Error>::decode(&mut &output[..]),
+            Ok(Err(Psp22Error::InsufficientBalance))
+        );
+
+        let output = token.dispatch(alice, selectors::BALANCE_OF, &bob.encode(), 0).unwrap();
+        assert_eq!(Balance::decode(&mut &output[..]), Ok(30));
+        assert_eq!(token.dispatch(alice, [0; 4], &[], 0), Err(()));
+    }
+}
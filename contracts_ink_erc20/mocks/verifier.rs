@@ -0,0 +1,78 @@
+use crate::{AccountId, MockContract};
+use scale::{Decode, Encode};
+
+/// 证明验证合约 verify(proof: Vec<u8>, public_inputs: [u8; 32]) -> bool 的选择器
+pub const VERIFY: [u8; 4] = ink_lang::selector_bytes!("verify");
+
+/// 证明验证合约：只接受用 accept 登记过的 (证明, 公开输入)，与真实的证明一样绑定公开输入；
+/// 可以设置为执行失败以模拟验证合约故障
+#[derive(Debug, Clone, Default)]
+pub struct MockVerifier {
+    accepted: Vec<(Vec<u8>, [u8; 32])>,
+    reverting: bool,
+    calls: Vec<(Vec<u8>, [u8; 32])>,
+}
+
+impl MockVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 之后对 proof 与 public_inputs 的验证返回 true
+    pub fn accept(&mut self, proof: Vec<u8>, public_inputs: [u8; 32]) {
+        self.accepted.push((proof, public_inputs));
+    }
+
+    /// 撤销登记过的证明，之后的验证返回 false
+    pub fn reject_all(&mut self) {
+        self.accepted.clear();
+    }
+
+    pub fn set_reverting(&mut self, reverting: bool) {
+        self.reverting = reverting;
+    }
+
+    /// 成功执行的调用收到的证明与公开输入，包括返回 false 的
+    pub fn calls(&self) -> &[(Vec<u8>, [u8; 32])] {
+        &self.calls
+    }
+}
+
+impl MockContract for MockVerifier {
+    fn dispatch(&mut self, _caller: AccountId, selector: [u8; 4], input: &[u8], _gas_limit: u64) -> Result<Vec<u8>, ()> {
+        if self.reverting || selector != VERIFY {
+            return Err(());
+        }
+        let arguments = <(Vec<u8>, [u8; 32])>::decode(&mut &input[..]).map_err(|_| ())?;
+        let verified = self.accepted.contains(&arguments);
+        self.calls.push(arguments);
+        Ok(verified.encode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifier_accepts_only_registered_proofs() {
+        let caller = AccountId::from([0x7; 32]);
+        let mut verifier = MockVerifier::new();
+        let input = (vec![1u8, 2], [0x5u8; 32]).encode();
+        assert_eq!(verifier.dispatch(caller, VERIFY, &input, 10), Ok(false.encode()));
+
+        verifier.accept(vec![1, 2], [0x5; 32]);
+        assert_eq!(verifier.dispatch(caller, VERIFY, &input, 10), Ok(true.encode()));
+        let other_inputs = (vec![1u8, 2], [0x6u8; 32]).encode();
+        assert_eq!(verifier.dispatch(caller, VERIFY, &other_inputs, 10), Ok(false.encode()));
+
+        verifier.set_reverting(true);
+        assert_eq!(verifier.dispatch(caller, VERIFY, &input, 10), Err(()));
+        verifier.set_reverting(false);
+        assert_eq!(verifier.dispatch(caller, [0; 4], &input, 10), Err(()));
+        assert_eq!(verifier.dispatch(caller, VERIFY, &[0xFF], 10), Err(()));
+        verifier.reject_all();
+        assert_eq!(verifier.dispatch(caller, VERIFY, &input, 10), Ok(false.encode()));
+        assert_eq!(verifier.calls().len(), 4);
+    }
+}
@@ -0,0 +1,38 @@
+// 只通过 api 模块使用合约，确保下游依赖的导出项保持可用
+use contracts_ink_erc20::api::{self, ContractsInkErc20, Error, Event, Transfer};
+use ink_env::AccountId;
+
+fn recorded_events() -> Vec<Event> {
+    ink_env::test::recorded_events()
+        .map(|event| <Event as scale::Decode>::decode(&mut &event.data[..]).expect("invalid event data"))
+        .collect()
+}
+
+#[ink_lang::test]
+fn api_exports_are_usable() {
+    let mut contract = ContractsInkErc20::new(100);
+    let bob = AccountId::from([0x2; 32]);
+    assert_eq!(contract.transfer(bob, 10), Ok(()));
+    assert_eq!(contract.balance_of(bob), 10);
+    assert_eq!(
+        contract.transfer(bob, 100),
+        Err(Error::InsufficientBalance { required: 100, available: 90 })
+    );
+
+    match recorded_events().last() {
+        Some(Event::Transfer(Transfer { to, value, sequence, .. })) => {
+            assert_eq!(to, &Some(bob));
+            assert_eq!(value, &10);
+            assert_eq!(sequence, &2);
+        }
+        _ => panic!("expected Transfer event"),
+    }
+
+    // 链下按导出的类型解码 account_overview 的返回值
+    let overview = scale::Encode::encode(&contract.account_overview(bob, vec![]));
+    let decoded = <api::AccountOverview as scale::Decode>::decode(&mut &overview[..]).expect("invalid overview");
+    assert_eq!((decoded.version, decoded.balance), (api::ACCOUNT_OVERVIEW_VERSION, 10));
+
+    assert_eq!(api::selectors::TRANSFER, ink_lang::selector_bytes!("transfer"));
+    assert!(api::Config::default().emit_genesis_event);
+}
@@ -0,0 +1,40 @@
+// 以非默认特性重新编译合约并运行全部单元测试。新的特性在这里的 FEATURES 中加一项，不再单独写一个集成测试：
+// balance-u64 检查转账、手续费与各项上限在 u64 余额下同样不会溢出；testnet 编译 onboard 与用户名，
+// 默认构建中的 onboard_is_compiled_out_without_the_testnet_feature 检查生产环境的构建中它们不生效
+use std::path::Path;
+use std::process::Command;
+
+// (特性, 该特性下必须通过的测试)
+const FEATURES: &[(&str, &str)] = &[
+    ("balance-u64", "balance_u64_tests::"),
+    ("testnet", "onboard_claims_approves_and_registers_a_username_atomically"),
+];
+
+#[test]
+fn contract_tests_pass_with_each_feature() {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    for (feature, required) in FEATURES {
+        // 每个特性使用单独的 target 目录，避免与外层 cargo test 争用构建锁，也避免特性切换导致反复重新编译
+        let target_dir = Path::new(manifest_dir).join("target").join("features").join(feature);
+        let output = Command::new(env!("CARGO"))
+            .current_dir(manifest_dir)
+            .args(["test", "--lib", "--features", feature, "--target-dir"])
+            .arg(&target_dir)
+            .output()
+            .expect("failed to run cargo");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            output.status.success(),
+            "tests with {} failed:\n{}\n{}",
+            feature,
+            stdout,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert!(
+            stdout.lines().any(|line| line.contains(required) && line.ends_with("... ok")),
+            "no passing `{}` test with {}",
+            required,
+            feature
+        );
+    }
+}
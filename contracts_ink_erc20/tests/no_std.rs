@@ -0,0 +1,35 @@
+// 防止仅在 std 下可用的代码混入合约：链上合约以 no_std 编译为 wasm，这里实际编译一次 no_std 目标。
+// 需要 nightly 工具链与 wasm32-unknown-unknown 目标，默认不运行；CI 中以 cargo +nightly test -- --ignored
+// 运行，并另外用 cargo contract build 构建合约
+use std::path::Path;
+use std::process::Command;
+
+const WASM_TARGET: &str = "wasm32-unknown-unknown";
+
+#[test]
+#[ignore = "requires a nightly toolchain with the wasm32-unknown-unknown target"]
+fn contract_compiles_without_std() {
+    if !wasm_target_installed() {
+        eprintln!("skipping: the {} target is not installed", WASM_TARGET);
+        return;
+    }
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    // 使用单独的 target 目录，避免与外层 cargo test 争用构建锁
+    let target_dir = Path::new(manifest_dir).join("target").join("no_std_check");
+    let output = Command::new(env!("CARGO"))
+        .current_dir(manifest_dir)
+        .args(["check", "--no-default-features", "--target", WASM_TARGET, "--target-dir"])
+        .arg(&target_dir)
+        .output()
+        .expect("failed to run cargo");
+    assert!(output.status.success(), "no_std build failed:\n{}", String::from_utf8_lossy(&output.stderr));
+}
+
+fn wasm_target_installed() -> bool {
+    Command::new("rustc")
+        .args(["--print", "sysroot"])
+        .output()
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+        .is_some_and(|sysroot| Path::new(&sysroot).join("lib/rustlib").join(WASM_TARGET).exists())
+}